@@ -1,10 +1,118 @@
 // Wolffia - Tauri Commands
 // Rust commands for desktop functionality
 
+use serde::Serialize;
 use tauri::{Manager, AppHandle};
 use std::fs;
 use std::path::PathBuf;
 
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+
+#[cfg(target_os = "linux")]
+use dbus::blocking::SyncConnection;
+#[cfg(target_os = "linux")]
+use std::time::Duration;
+use std::sync::Mutex;
+
+/// Metadata describing a single directory entry, as returned by `list_dir`
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryMetaData {
+    pub name: String,
+    pub path: String,
+    pub size: u64,
+    pub is_directory: bool,
+    pub is_file: bool,
+    pub is_symlink: bool,
+    pub created: Option<u64>,
+    pub modified: Option<u64>,
+    pub accessed: Option<u64>,
+    pub child_count: Option<usize>,
+    pub permissions: String,
+}
+
+fn system_time_to_millis(time: std::io::Result<std::time::SystemTime>) -> Option<u64> {
+    time.ok()?
+        .duration_since(std::time::UNIX_EPOCH)
+        .ok()
+        .map(|d| d.as_millis() as u64)
+}
+
+#[cfg(unix)]
+fn permission_string(metadata: &fs::Metadata) -> String {
+    let mode = metadata.permissions().mode();
+    let triple = |shift: u32| -> String {
+        let bits = (mode >> shift) & 0o7;
+        format!(
+            "{}{}{}",
+            if bits & 0o4 != 0 { "r" } else { "-" },
+            if bits & 0o2 != 0 { "w" } else { "-" },
+            if bits & 0o1 != 0 { "x" } else { "-" }
+        )
+    };
+    format!(
+        "0o{:o} ({}{}{})",
+        mode & 0o777,
+        triple(6),
+        triple(3),
+        triple(0)
+    )
+}
+
+#[cfg(not(unix))]
+fn permission_string(metadata: &fs::Metadata) -> String {
+    if metadata.permissions().readonly() {
+        "read-only".to_string()
+    } else {
+        "read-write".to_string()
+    }
+}
+
+/// List the contents of a directory with rich per-entry metadata
+#[tauri::command]
+pub fn list_dir(path: String) -> Result<Vec<EntryMetaData>, String> {
+    let entries = fs::read_dir(&path).map_err(|e| format!("Failed to read directory: {}", e))?;
+
+    let mut result = Vec::new();
+    for entry in entries {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(_) => continue,
+        };
+
+        let metadata = match entry.metadata() {
+            Ok(metadata) => metadata,
+            Err(_) => continue,
+        };
+
+        let is_directory = metadata.is_dir();
+        let child_count = if is_directory {
+            fs::read_dir(entry.path())
+                .ok()
+                .map(|dir| dir.filter_map(|e| e.ok()).count())
+        } else {
+            None
+        };
+
+        result.push(EntryMetaData {
+            name: entry.file_name().to_string_lossy().to_string(),
+            path: entry.path().to_string_lossy().to_string(),
+            size: metadata.len(),
+            is_directory,
+            is_file: metadata.is_file(),
+            is_symlink: metadata.file_type().is_symlink(),
+            created: system_time_to_millis(metadata.created()),
+            modified: system_time_to_millis(metadata.modified()),
+            accessed: system_time_to_millis(metadata.accessed()),
+            child_count,
+            permissions: permission_string(&metadata),
+        });
+    }
+
+    Ok(result)
+}
+
 /// Get the app data directory
 #[tauri::command]
 pub fn get_data_dir(app: AppHandle) -> Result<String, String> {
@@ -21,19 +129,74 @@ pub fn read_file(path: String) -> Result<String, String> {
         .map_err(|e| format!("Failed to read file: {}", e))
 }
 
-/// Write content to a file
-#[tauri::command]
-pub fn write_file(path: String, content: String) -> Result<(), String> {
-    // Ensure parent directory exists
-    if let Some(parent) = PathBuf::from(&path).parent() {
+/// Create the parent directory of `path` if it doesn't already exist
+fn ensure_parent_dir(path: &str) -> Result<(), String> {
+    if let Some(parent) = PathBuf::from(path).parent() {
         fs::create_dir_all(parent)
             .map_err(|e| format!("Failed to create directory: {}", e))?;
     }
-    
+    Ok(())
+}
+
+/// Write content to a file
+#[tauri::command]
+pub fn write_file(path: String, content: String) -> Result<(), String> {
+    ensure_parent_dir(&path)?;
+
     fs::write(&path, content)
         .map_err(|e| format!("Failed to write file: {}", e))
 }
 
+/// Read a file from disk as raw bytes, for binary payloads
+#[tauri::command]
+pub fn read_file_bytes(path: String) -> Result<Vec<u8>, String> {
+    fs::read(&path)
+        .map_err(|e| format!("Failed to read file: {}", e))
+}
+
+/// Write raw bytes to a file, for binary payloads
+#[tauri::command]
+pub fn write_file_bytes(path: String, content: Vec<u8>) -> Result<(), String> {
+    ensure_parent_dir(&path)?;
+
+    fs::write(&path, content)
+        .map_err(|e| format!("Failed to write file: {}", e))
+}
+
+/// Await the result of a closure-based dialog picker without blocking a worker thread.
+///
+/// `spawn` receives a boxed callback to hand to the picker; the picker invokes it
+/// exactly once with the chosen path(s). On Linux the send is bounced through the
+/// GLib main context, since GTK dialog callbacks aren't guaranteed to fire on a
+/// thread that's safe to touch GTK/tauri state from.
+async fn resolve_picker<T, F>(spawn: F) -> Option<T>
+where
+    T: Send + 'static,
+    F: FnOnce(Box<dyn FnOnce(Option<T>) + Send>),
+{
+    let (tx, rx) = tokio::sync::oneshot::channel::<Option<T>>();
+    let tx = Mutex::new(Some(tx));
+
+    spawn(Box::new(move |result| {
+        let Some(tx) = tx.lock().unwrap().take() else {
+            return;
+        };
+
+        #[cfg(target_os = "linux")]
+        {
+            glib::MainContext::default().invoke(move || {
+                let _ = tx.send(result);
+            });
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            let _ = tx.send(result);
+        }
+    }));
+
+    rx.await.unwrap_or(None)
+}
+
 /// Show save dialog and return selected path
 #[tauri::command]
 pub async fn show_save_dialog(
@@ -42,16 +205,16 @@ pub async fn show_save_dialog(
     filters: Vec<(String, Vec<String>)>
 ) -> Result<Option<String>, String> {
     use tauri_plugin_dialog::DialogExt;
-    
+
     let mut dialog = app.dialog().file();
-    
+
     for (name, extensions) in filters {
         dialog = dialog.add_filter(&name, &extensions.iter().map(|s| s.as_str()).collect::<Vec<_>>());
     }
-    
+
     dialog = dialog.set_file_name(&default_name);
-    
-    let path = dialog.blocking_save_file();
+
+    let path = resolve_picker(|cb| dialog.save_file(move |p| cb(p))).await;
     Ok(path.map(|p| p.to_string()))
 }
 
@@ -60,21 +223,32 @@ pub async fn show_save_dialog(
 pub async fn show_open_dialog(
     app: AppHandle,
     multiple: bool,
+    directory: bool,
     filters: Vec<(String, Vec<String>)>
 ) -> Result<Vec<String>, String> {
     use tauri_plugin_dialog::DialogExt;
-    
+
     let mut dialog = app.dialog().file();
-    
-    for (name, extensions) in filters {
-        dialog = dialog.add_filter(&name, &extensions.iter().map(|s| s.as_str()).collect::<Vec<_>>());
+
+    if !directory {
+        for (name, extensions) in filters {
+            dialog = dialog.add_filter(&name, &extensions.iter().map(|s| s.as_str()).collect::<Vec<_>>());
+        }
     }
-    
-    if multiple {
-        let paths = dialog.blocking_pick_files();
+
+    if directory {
+        if multiple {
+            let paths = resolve_picker(|cb| dialog.pick_folders(move |p| cb(p))).await;
+            Ok(paths.unwrap_or_default().into_iter().map(|p| p.to_string()).collect())
+        } else {
+            let path = resolve_picker(|cb| dialog.pick_folder(move |p| cb(p))).await;
+            Ok(path.map(|p| vec![p.to_string()]).unwrap_or_default())
+        }
+    } else if multiple {
+        let paths = resolve_picker(|cb| dialog.pick_files(move |p| cb(p))).await;
         Ok(paths.unwrap_or_default().into_iter().map(|p| p.to_string()).collect())
     } else {
-        let path = dialog.blocking_pick_file();
+        let path = resolve_picker(|cb| dialog.pick_file(move |p| cb(p))).await;
         Ok(path.map(|p| vec![p.to_string()]).unwrap_or_default())
     }
 }
@@ -84,3 +258,131 @@ pub async fn show_open_dialog(
 pub fn is_desktop() -> bool {
     true
 }
+
+/// Holds the long-lived D-Bus connection used by `show_in_folder` on Linux
+#[cfg(target_os = "linux")]
+pub struct DbusState(pub Mutex<Option<SyncConnection>>);
+
+#[cfg(target_os = "linux")]
+fn show_in_folder_linux(state: &DbusState, path: &str) -> Result<(), String> {
+    // The freedesktop ShowItems method doesn't handle commas in file URIs well,
+    // so fall back to xdg-open in that case rather than risk a garbled request.
+    let guard = state.0.lock().map_err(|e| e.to_string())?;
+    if let Some(conn) = guard.as_ref() {
+        if !path.contains(',') {
+            let proxy = conn.with_proxy(
+                "org.freedesktop.FileManager1",
+                "/org/freedesktop/FileManager1",
+                Duration::from_secs(5),
+            );
+            let uri = format!("file://{}", path);
+            let result: Result<(), dbus::Error> =
+                proxy.method_call("org.freedesktop.FileManager1", "ShowItems", (vec![uri], ""));
+            if result.is_ok() {
+                return Ok(());
+            }
+        }
+    }
+
+    let parent = PathBuf::from(path)
+        .parent()
+        .map(|p| p.to_path_buf())
+        .unwrap_or_else(|| PathBuf::from(path));
+    std::process::Command::new("xdg-open")
+        .arg(parent)
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("Failed to open file manager: {}", e))
+}
+
+/// Reveal a file or directory in the OS file manager, with it selected/highlighted.
+///
+/// On Linux, when the D-Bus file manager call is unavailable, fails, or the path
+/// contains a comma, the parent directory is opened with `xdg-open` instead and
+/// nothing in it is selected.
+#[tauri::command]
+pub fn show_in_folder(
+    path: String,
+    #[cfg(target_os = "linux")] state: tauri::State<DbusState>,
+) -> Result<(), String> {
+    #[cfg(target_os = "windows")]
+    {
+        std::process::Command::new("explorer")
+            .arg(format!("/select,{}", path))
+            .spawn()
+            .map(|_| ())
+            .map_err(|e| format!("Failed to open file manager: {}", e))
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        std::process::Command::new("open")
+            .args(["-R", &path])
+            .spawn()
+            .map(|_| ())
+            .map_err(|e| format!("Failed to open file manager: {}", e))
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        show_in_folder_linux(&state, &path)
+    }
+}
+
+/// Show a native info/warning/error message dialog
+#[tauri::command]
+pub fn show_message_dialog(
+    app: AppHandle,
+    title: Option<String>,
+    message: String,
+    level: Option<String>,
+) -> Result<(), String> {
+    use tauri_plugin_dialog::{DialogExt, MessageDialogKind};
+
+    let kind = match level.as_deref() {
+        Some("warning") => MessageDialogKind::Warning,
+        Some("error") => MessageDialogKind::Error,
+        _ => MessageDialogKind::Info,
+    };
+
+    let title = title.unwrap_or_else(|| app.package_info().name.clone());
+
+    let mut dialog = app.dialog().message(message).title(title).kind(kind);
+
+    #[cfg(any(target_os = "windows", target_os = "macos"))]
+    {
+        if let Some(window) = app.get_webview_window("main") {
+            dialog = dialog.parent(&window);
+        }
+    }
+
+    dialog.blocking_show();
+    Ok(())
+}
+
+/// Show a native yes/no confirmation dialog, returning the user's choice
+#[tauri::command]
+pub fn show_confirm_dialog(
+    app: AppHandle,
+    title: Option<String>,
+    message: String,
+) -> Result<bool, String> {
+    use tauri_plugin_dialog::{DialogExt, MessageDialogButtons};
+
+    let title = title.unwrap_or_else(|| app.package_info().name.clone());
+
+    let mut dialog = app
+        .dialog()
+        .message(message)
+        .title(title)
+        .buttons(MessageDialogButtons::YesNo);
+
+    #[cfg(any(target_os = "windows", target_os = "macos"))]
+    {
+        if let Some(window) = app.get_webview_window("main") {
+            dialog = dialog.parent(&window);
+        }
+    }
+
+    Ok(dialog.blocking_show())
+}
@@ -22,14 +22,28 @@ pub fn run() {
                 window.show().unwrap();
             }
 
+            // The D-Bus connection backing `show_in_folder` is best-effort: if the
+            // session bus isn't reachable we still run, just falling back to xdg-open.
+            #[cfg(target_os = "linux")]
+            {
+                let conn = dbus::blocking::SyncConnection::new_session().ok();
+                app.manage(commands::DbusState(std::sync::Mutex::new(conn)));
+            }
+
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
             commands::get_data_dir,
             commands::read_file,
             commands::write_file,
+            commands::read_file_bytes,
+            commands::write_file_bytes,
+            commands::list_dir,
             commands::show_save_dialog,
             commands::show_open_dialog,
+            commands::show_in_folder,
+            commands::show_message_dialog,
+            commands::show_confirm_dialog,
             commands::is_desktop,
         ])
         .run(tauri::generate_context!())